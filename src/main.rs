@@ -1,19 +1,30 @@
 use std::cmp::Ordering;
+use std::io::Read as _;
+use std::str::FromStr;
 
 use anyhow::Context;
+use getopts::Options;
 
 const CONTROL_GREY: &str = "\u{001b}[38;5;243m";
 const CONTROL_GREEN: &str = "\u{001b}[32m";
 const CONTROL_RED: &str = "\u{001b}[31m";
+const CONTROL_YELLOW: &str = "\u{001b}[33m";
 const CONTROL_RESET: &str = "\u{001b}[0m";
 
 #[derive(PartialEq, Debug)]
 pub struct UciOption {
     name: String,
     value: f64,
+    /// The engine's declared type for this option (e.g. `"int"`), present
+    /// on the input side only.
+    kind: Option<String>,
     min: Option<f64>,
     max: Option<f64>,
     step: Option<f64>,
+    /// Any input-side fields beyond `step` (e.g. the SPSA learning-rate/
+    /// `c_end` field), kept verbatim so a reconstructed declaration line
+    /// round-trips with the same arity as the original.
+    tail: Vec<String>,
 }
 
 pub enum IOSort {
@@ -21,59 +32,149 @@ pub enum IOSort {
     Output,
 }
 
+/// The row type threaded through sorting and rendering: a matched
+/// `(before, after)` pair alongside its pre-computed fractional change.
+type Pair = ((UciOption, UciOption), f64);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum OutputFormat {
+    /// ANSI-colored, human-aligned table (the original behaviour).
+    Pretty,
+    /// Same table as `Pretty`, with the `CONTROL_*` escapes stripped.
+    Plain,
+    /// `name,before,after,fractional_change` rows.
+    Csv,
+    /// A JSON array of `{name, before, after, fractional_change}` objects.
+    Json,
+    /// `setoption name <NAME> value <VALUE>` lines, ready to paste into a
+    /// UCI `position`/`go` session to apply the tuned values.
+    Uci,
+    /// The engine's own parameter-declaration syntax, reconstructed from the
+    /// input side's `min`/`max`/`step` but with the tuned value substituted
+    /// in, ready to paste back into the engine's tunable-parameter list.
+    Config,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "pretty" => Ok(Self::Pretty),
+            "plain" => Ok(Self::Plain),
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            "uci" => Ok(Self::Uci),
+            "config" => Ok(Self::Config),
+            other => anyhow::bail!(
+                "Unknown format \"{other}\" (expected one of pretty, plain, csv, json, uci, config)"
+            ),
+        }
+    }
+}
+
+/// Checks whether `after` landed within one `step` of `before`'s declared
+/// `min`/`max`, which suggests the bound was too tight for the tune to
+/// explore properly. Returns a human-readable warning naming the pinned
+/// bound and a widened value to try next time.
+fn pinned_bound_warning(before: &UciOption, after: &UciOption) -> Option<String> {
+    let step = before.step?;
+    if let Some(min) = before.min
+        && (after.value - min).abs() <= step
+    {
+        let widened = min - (before.max.unwrap_or(min) - min);
+        return Some(format!(
+            "{CONTROL_YELLOW}!! {} converged at its min bound ({min}) -- consider widening min to {widened} !!{CONTROL_RESET}",
+            before.name
+        ));
+    }
+    if let Some(max) = before.max
+        && (max - after.value).abs() <= step
+    {
+        let widened = max + (max - before.min.unwrap_or(max));
+        return Some(format!(
+            "{CONTROL_YELLOW}!! {} converged at its max bound ({max}) -- consider widening max to {widened} !!{CONTROL_RESET}",
+            before.name
+        ));
+    }
+    None
+}
+
 // example output:
 // RFP_MARGIN, 73
 // example input:
 // RFP_MARGIN, int, 73.0, 40.0, 200.0, 10.0, 0.002
 
-pub fn parse_from_input(text: &str, sort: IOSort) -> anyhow::Result<Vec<UciOption>> {
+/// Splits a line into trimmed fields on `delimiter`, or on whichever of
+/// `,`/`\t` appears in the line when `delimiter` is `None`. Trimming makes
+/// this tolerant of the extra spaces that show up across different tuning
+/// server exports; only a *trailing* empty field (a stray trailing
+/// delimiter) is dropped, so a legitimately blank interior field (e.g. a
+/// missing `min`) doesn't shift every column after it.
+fn split_fields(line: &str, delimiter: Option<char>) -> Vec<&str> {
+    let delimiter = delimiter.unwrap_or(if line.contains('\t') { '\t' } else { ',' });
+    let mut fields: Vec<&str> = line.split(delimiter).map(str::trim).collect();
+    while fields.last().is_some_and(|field| field.is_empty()) {
+        fields.pop();
+    }
+    fields
+}
+
+pub fn parse_from_input(
+    text: &str,
+    sort: IOSort,
+    delimiter: Option<char>,
+) -> anyhow::Result<Vec<UciOption>> {
     text.lines()
         .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
         .map(|(i, l)| {
-            let mut parts = l.split(", ");
-            let name = parts
-                .next()
+            let fields = split_fields(l, delimiter);
+            let name = fields
+                .first()
                 .with_context(|| format!("No name part in line {i}: \"{}\"", l))?;
-            let val_index = match sort {
-                IOSort::Input => 1,
-                IOSort::Output => 0,
+            // Positions past `value` (min, max, step) are read defensively
+            // with `.get()`, so an extra trailing comment field - or a
+            // missing one - doesn't turn into a hard parse error.
+            let (kind, value_idx) = match sort {
+                IOSort::Input => (
+                    Some(
+                        fields
+                            .get(1)
+                            .with_context(|| format!("No type part in line {i}: \"{}\"", l))?
+                            .to_string(),
+                    ),
+                    2,
+                ),
+                IOSort::Output => (None, 1),
             };
-            let val = parts
-                .nth(val_index)
+            let val = fields
+                .get(value_idx)
                 .with_context(|| format!("No value part in line {i}: \"{}\"", l))?;
-            let min = parts.next().and_then(|s| s.parse().ok());
-            let max = parts.next().and_then(|s| s.parse().ok());
-            let step = parts.next().and_then(|s| s.parse().ok());
+            let min = fields.get(value_idx + 1).and_then(|s| s.parse().ok());
+            let max = fields.get(value_idx + 2).and_then(|s| s.parse().ok());
+            let step = fields.get(value_idx + 3).and_then(|s| s.parse().ok());
+            let tail = fields
+                .iter()
+                .skip(value_idx + 4)
+                .map(|s| s.to_string())
+                .collect();
             Ok(UciOption {
                 name: name.to_string(),
                 value: val.parse()?,
+                kind,
                 min,
                 max,
                 step,
+                tail,
             })
         })
         .collect()
 }
 
-fn main() -> anyhow::Result<()> {
-    // let url = "https://chess.swehosting.se/tune/7126/";
-    let url = std::env::args()
-        .nth(1)
-        .with_context(|| "NO URL ARGUMENT PROVIDED")?;
-    println!("FETCHING {url}");
-
-    let response = minreq::get(url).send()?;
-    let text = response.as_str()?;
-    anyhow::ensure!(
-        text.contains("</html>"),
-        "HTML CLOSING TAG NOT FOUND IN TEXT"
-    );
-    anyhow::ensure!(
-        200 == response.status_code,
-        "RESPONSE 200 OK NOT FOUND: {}",
-        response.status_code
-    );
-
+/// Pulls the raw SPSA input/output text out of the `spsa-input`/`spsa-output`
+/// marked-up regions of a tuning-server page.
+fn try_extract_spsa_blocks(text: &str) -> anyhow::Result<(&str, &str)> {
     let (_, rest) = text
         .split_once("spsa-input")
         .with_context(|| "Did not find \"spsa-input\" in page.")?;
@@ -92,25 +193,63 @@ fn main() -> anyhow::Result<()> {
     let (output, _) = rest
         .split_once('<')
         .with_context(|| "Did not find start of tag after SPSA output data.")?;
+    Ok((input, output))
+}
 
-    // let input = include_str!("../input.txt");
-    // let output = include_str!("../output.txt");
-    let input = parse_from_input(input, IOSort::Input)?;
-    let output = parse_from_input(output, IOSort::Output)?;
+/// Extracts the SPSA input/output blocks from a fetched page. When the
+/// `spsa-input`/`spsa-output` markers aren't present - some tuning servers
+/// don't wrap their data that way - falls back to treating the whole body
+/// as raw SPSA input text, with an empty output block, rather than failing
+/// outright. `--input`/`--output` are not read when `--url` is given.
+fn extract_spsa_blocks(text: &str) -> (String, String) {
+    match try_extract_spsa_blocks(text) {
+        Ok((input, output)) => (input.to_string(), output.to_string()),
+        Err(_) => {
+            eprintln!(
+                "warning: no \"spsa-input\"/\"spsa-output\" markers found in page; treating the whole body as raw SPSA input"
+            );
+            (text.to_string(), String::new())
+        }
+    }
+}
 
-    let mut pairs = input
-        .into_iter()
-        .zip(output)
-        .map(|p| {
-            let range = p.0.max.unwrap_or(f64::INFINITY) - p.0.min.unwrap_or(f64::NEG_INFINITY);
-            let diff = p.1.value - p.0.value;
-            let frac = diff / range;
-            (p, frac)
-        })
-        .collect::<Vec<_>>();
+fn fetch_spsa_page(url: &str) -> anyhow::Result<String> {
+    println!("FETCHING {url}");
+    let response = minreq::get(url).send()?;
+    let text = response.as_str()?.to_string();
+    anyhow::ensure!(
+        text.contains("</html>"),
+        "HTML CLOSING TAG NOT FOUND IN TEXT"
+    );
+    anyhow::ensure!(
+        200 == response.status_code,
+        "RESPONSE 200 OK NOT FOUND: {}",
+        response.status_code
+    );
+    Ok(text)
+}
 
-    pairs.sort_by(|(_, ak), (_, bk)| f64::total_cmp(&bk.abs(), &ak.abs()));
+/// Reads a SPSA input/output source from a local file, or from stdin when
+/// `path` is `-`.
+fn read_source(path: &str) -> anyhow::Result<String> {
+    if path == "-" {
+        let mut buf = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buf)
+            .with_context(|| "Failed to read stdin")?;
+        Ok(buf)
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read \"{path}\""))
+    }
+}
+
+fn strip_control_codes(s: &str) -> String {
+    [CONTROL_GREY, CONTROL_GREEN, CONTROL_RED, CONTROL_YELLOW, CONTROL_RESET]
+        .iter()
+        .fold(s.to_string(), |acc, code| acc.replace(code, ""))
+}
 
+fn render_table(pairs: Vec<Pair>, colored: bool) {
     let line_width = 45;
     println!();
     println!(
@@ -118,6 +257,7 @@ fn main() -> anyhow::Result<()> {
         pad = " ".repeat(line_width - 20)
     );
     println!("{}", "-".repeat(line_width + 5));
+    let mut pinned_warnings = Vec::new();
     for ((before, after), _) in pairs {
         assert_eq!(before.name, after.name);
         let control = match after.value.total_cmp(&before.value) {
@@ -125,7 +265,7 @@ fn main() -> anyhow::Result<()> {
             Ordering::Equal => CONTROL_GREY,
             Ordering::Greater => CONTROL_GREEN,
         };
-        println!(
+        let line = format!(
             "{} {pad} {before} -> {control}{after}{CONTROL_RESET} {tail}",
             before.name,
             pad = ".".repeat(36usize.saturating_sub(before.name.len() + before.value.abs().log10() as usize + usize::from(before.value < 0.0))
@@ -134,7 +274,158 @@ fn main() -> anyhow::Result<()> {
             after = after.value,
             tail = ".".repeat(5usize.saturating_sub(after.value.abs().log10() as usize + usize::from(after.value < 0.0)))
         );
+        println!("{}", if colored { line } else { strip_control_codes(&line) });
+        if let Some(warning) = pinned_bound_warning(&before, &after) {
+            pinned_warnings.push(if colored {
+                warning
+            } else {
+                strip_control_codes(&warning)
+            });
+        }
+    }
+
+    if !pinned_warnings.is_empty() {
+        println!();
+        for warning in pinned_warnings {
+            println!("{warning}");
+        }
+    }
+}
+
+fn render_csv(pairs: &[Pair]) {
+    println!("name,before,after,fractional_change");
+    for ((before, after), frac) in pairs {
+        println!("{},{},{},{frac}", before.name, before.value, after.value);
     }
+}
+
+fn render_json(pairs: &[Pair]) {
+    println!("[");
+    for (i, ((before, after), frac)) in pairs.iter().enumerate() {
+        let comma = if i + 1 == pairs.len() { "" } else { "," };
+        println!(
+            "  {{\"name\": \"{}\", \"before\": {}, \"after\": {}, \"fractional_change\": {}}}{comma}",
+            before.name, before.value, after.value, frac
+        );
+    }
+    println!("]");
+}
+
+fn render_uci(pairs: &[Pair]) {
+    for ((_, after), _) in pairs {
+        println!("setoption name {} value {}", after.name, after.value);
+    }
+}
+
+/// Re-renders each tuned option in its original declaration syntax (the
+/// input side's `type`/`min`/`max`/`step`), but with the tuned `after.value`
+/// substituted in, so the line can be pasted straight back into the engine.
+fn config_line(before: &UciOption, after: &UciOption) -> String {
+    let kind = before.kind.as_deref().unwrap_or("int");
+    let mut line = format!("{}, {kind}, {}", after.name, after.value);
+    // A missing bound still occupies its column - emitting nothing would
+    // shift every later field left and corrupt the round-trip.
+    for bound in [before.min, before.max, before.step] {
+        line.push_str(&format!(", {}", bound.map_or(String::new(), |b| b.to_string())));
+    }
+    for field in &before.tail {
+        line.push_str(&format!(", {field}"));
+    }
+    line
+}
+
+fn render_config(pairs: &[Pair]) {
+    for ((before, after), _) in pairs {
+        println!("{}", config_line(before, after));
+    }
+}
+
+fn render_pairs(pairs: Vec<Pair>, format: OutputFormat) {
+    match format {
+        OutputFormat::Pretty => render_table(pairs, true),
+        OutputFormat::Plain => render_table(pairs, false),
+        OutputFormat::Csv => render_csv(&pairs),
+        OutputFormat::Json => render_json(&pairs),
+        OutputFormat::Uci => render_uci(&pairs),
+        OutputFormat::Config => render_config(&pairs),
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+
+    let mut opts = Options::new();
+    opts.optopt("", "url", "fetch SPSA input/output from a tuning server URL", "URL");
+    opts.optopt("", "input", "read SPSA input from FILE (\"-\" for stdin)", "FILE");
+    opts.optopt("", "output", "read SPSA output from FILE (\"-\" for stdin)", "FILE");
+    opts.optopt(
+        "f",
+        "format",
+        "output format: pretty, plain, csv, json, uci, or config (default: pretty)",
+        "FORMAT",
+    );
+    opts.optopt(
+        "",
+        "delimiter",
+        "field delimiter for SPSA dumps (auto-detected between comma and tab if unset)",
+        "CHAR",
+    );
+    opts.optflag("h", "help", "print this help menu");
+
+    let matches = opts
+        .parse(&args[1..])
+        .with_context(|| "Failed to parse command-line arguments")?;
+
+    if matches.opt_present("help") {
+        print!("{}", opts.usage(&format!("Usage: {} [options]", args[0])));
+        return Ok(());
+    }
+
+    let format = matches
+        .opt_str("format")
+        .map_or(Ok(OutputFormat::Pretty), |s| s.parse())?;
+
+    let delimiter = matches
+        .opt_str("delimiter")
+        .map(|s| s.chars().next().with_context(|| "Empty --delimiter value"))
+        .transpose()?;
+
+    let (input_text, output_text) = if let Some(url) = matches.opt_str("url") {
+        let page = fetch_spsa_page(&url)?;
+        extract_spsa_blocks(&page)
+    } else {
+        let input_path = matches
+            .opt_str("input")
+            .with_context(|| "Must provide either --url or --input/--output")?;
+        let output_path = matches
+            .opt_str("output")
+            .with_context(|| "--input was provided without a matching --output")?;
+        (read_source(&input_path)?, read_source(&output_path)?)
+    };
+
+    let input = parse_from_input(&input_text, IOSort::Input, delimiter)?;
+    let output = parse_from_input(&output_text, IOSort::Output, delimiter)?;
+
+    let mut pairs = input
+        .into_iter()
+        .zip(output)
+        .map(|p| {
+            let range = p.0.max.unwrap_or(f64::INFINITY) - p.0.min.unwrap_or(f64::NEG_INFINITY);
+            let diff = p.1.value - p.0.value;
+            let frac = diff / range;
+            (p, frac)
+        })
+        .collect::<Vec<_>>();
+
+    pairs.sort_by(|(_, ak), (_, bk)| f64::total_cmp(&bk.abs(), &ak.abs()));
+
+    if pairs.is_empty() {
+        eprintln!(
+            "warning: no (before, after) pairs were matched - check that --input/--output (or the scraped page) actually contain SPSA data"
+        );
+    }
+
+    render_pairs(pairs, format);
 
     Ok(())
 }
@@ -159,7 +450,7 @@ HISTORY_PRUNING_MARGIN, -2474";
     fn example_works() {
         use crate::{parse_from_input, IOSort, UciOption};
 
-        let options = parse_from_input(EXAMPLE_INPUT, IOSort::Input).unwrap();
+        let options = parse_from_input(EXAMPLE_INPUT, IOSort::Input, None).unwrap();
 
         assert_eq!(
             options,
@@ -167,49 +458,61 @@ HISTORY_PRUNING_MARGIN, -2474";
                 UciOption {
                     name: "ASPIRATION_WINDOW".into(),
                     value: 6.0,
+                    kind: Some("int".into()),
                     min: Some(1.0),
                     max: Some(50.0),
                     step: Some(3.0),
+                    tail: vec!["0.002".into()],
                 },
                 UciOption {
                     name: "RFP_MARGIN".into(),
                     value: 73.0,
+                    kind: Some("int".into()),
                     min: Some(40.0),
                     max: Some(200.0),
                     step: Some(10.0),
+                    tail: vec!["0.002".into()],
                 },
                 UciOption {
                     name: "RFP_IMPROVING_MARGIN".into(),
                     value: 58.0,
+                    kind: Some("int".into()),
                     min: Some(30.0),
                     max: Some(150.0),
                     step: Some(10.0),
+                    tail: vec!["0.002".into()],
                 },
                 UciOption {
                     name: "DO_DEEPER_DEPTH_MARGIN".into(),
                     value: 11.0,
+                    kind: Some("int".into()),
                     min: Some(1.0),
                     max: Some(50.0),
                     step: Some(2.0),
+                    tail: vec!["0.002".into()],
                 },
                 UciOption {
                     name: "HISTORY_PRUNING_DEPTH".into(),
                     value: 7.0,
+                    kind: Some("int".into()),
                     min: Some(2.0),
                     max: Some(14.0),
                     step: Some(1.0),
+                    tail: vec!["0.002".into()],
                 },
                 UciOption {
                     name: "HISTORY_PRUNING_MARGIN".into(),
                     value: -2500.0,
+                    kind: Some("int".into()),
                     min: Some(-5000.0),
                     max: Some(1000.0),
                     step: Some(500.0),
+                    tail: vec!["0.002".into()],
                 },
             ]
         );
 
-        let options = parse_from_input(EXAMPLE_OUTPUT, IOSort::Output).unwrap();
+        let options = parse_from_input(EXAMPLE_OUTPUT, IOSort::Output, None).unwrap();
 
         assert_eq!(
             options,
@@ -217,46 +520,184 @@ HISTORY_PRUNING_MARGIN, -2474";
                 UciOption {
                     name: "ASPIRATION_WINDOW".into(),
                     value: 5.0,
+                    kind: None,
                     min: None,
                     max: None,
                     step: None,
+                    tail: vec![],
                 },
                 UciOption {
                     name: "RFP_MARGIN".into(),
                     value: 73.0,
+                    kind: None,
                     min: None,
                     max: None,
                     step: None,
+                    tail: vec![],
                 },
                 UciOption {
                     name: "RFP_IMPROVING_MARGIN".into(),
                     value: 58.0,
+                    kind: None,
                     min: None,
                     max: None,
                     step: None,
+                    tail: vec![],
                 },
                 UciOption {
                     name: "DO_DEEPER_DEPTH_MARGIN".into(),
                     value: 11.0,
+                    kind: None,
                     min: None,
                     max: None,
                     step: None,
+                    tail: vec![],
                 },
                 UciOption {
                     name: "HISTORY_PRUNING_DEPTH".into(),
                     value: 7.0,
+                    kind: None,
                     min: None,
                     max: None,
                     step: None,
+                    tail: vec![],
                 },
                 UciOption {
                     name: "HISTORY_PRUNING_MARGIN".into(),
                     value: -2474.0,
+                    kind: None,
                     min: None,
                     max: None,
                     step: None,
+                    tail: vec![],
                 },
             ]
         );
     }
+
+    #[test]
+    fn pinned_bound_warning_detects_edges() {
+        use crate::{pinned_bound_warning, UciOption};
+
+        let min_pinned = UciOption {
+            name: "RFP_MARGIN".into(),
+            value: 73.0,
+            kind: Some("int".into()),
+            min: Some(40.0),
+            max: Some(200.0),
+            step: Some(10.0),
+            tail: vec!["0.002".into()],
+        };
+        let at_min = UciOption {
+            name: "RFP_MARGIN".into(),
+            value: 42.0,
+            kind: None,
+            min: None,
+            max: None,
+            step: None,
+            tail: vec![],
+        };
+        assert!(pinned_bound_warning(&min_pinned, &at_min).is_some());
+
+        let at_max = UciOption {
+            name: "RFP_MARGIN".into(),
+            value: 195.0,
+            kind: None,
+            min: None,
+            max: None,
+            step: None,
+            tail: vec![],
+        };
+        assert!(pinned_bound_warning(&min_pinned, &at_max).is_some());
+
+        let in_range = UciOption {
+            name: "RFP_MARGIN".into(),
+            value: 80.0,
+            kind: None,
+            min: None,
+            max: None,
+            step: None,
+            tail: vec![],
+        };
+        assert!(pinned_bound_warning(&min_pinned, &in_range).is_none());
+    }
+
+    #[test]
+    fn output_format_parses_known_names_and_rejects_others() {
+        use crate::OutputFormat;
+
+        assert_eq!("pretty".parse::<OutputFormat>().unwrap(), OutputFormat::Pretty);
+        assert_eq!("plain".parse::<OutputFormat>().unwrap(), OutputFormat::Plain);
+        assert_eq!("csv".parse::<OutputFormat>().unwrap(), OutputFormat::Csv);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn parse_from_input_tolerates_tabs_whitespace_and_trailing_comment() {
+        use crate::{parse_from_input, IOSort, UciOption};
+
+        let messy = "  RFP_MARGIN \t int \t 73.0 \t 40.0 \t 200.0 \t 10.0 \t 0.002 \t # re-tuned 2026-01\n";
+        let options = parse_from_input(messy, IOSort::Input, None).unwrap();
+
+        assert_eq!(
+            options,
+            vec![UciOption {
+                name: "RFP_MARGIN".into(),
+                value: 73.0,
+                kind: Some("int".into()),
+                min: Some(40.0),
+                max: Some(200.0),
+                step: Some(10.0),
+                tail: vec!["0.002".into(), "# re-tuned 2026-01".into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_from_input_keeps_interior_blank_fields_aligned() {
+        use crate::{parse_from_input, IOSort, UciOption};
+
+        let missing_min = "RFP_MARGIN, int, 73, , 200, 10";
+        let options = parse_from_input(missing_min, IOSort::Input, None).unwrap();
+
+        assert_eq!(
+            options,
+            vec![UciOption {
+                name: "RFP_MARGIN".into(),
+                value: 73.0,
+                kind: Some("int".into()),
+                min: None,
+                max: Some(200.0),
+                step: Some(10.0),
+                tail: vec![],
+            }]
+        );
+    }
+
+    #[test]
+    fn config_line_keeps_columns_aligned_when_min_is_missing() {
+        use crate::{config_line, UciOption};
+
+        let before = UciOption {
+            name: "RFP_MARGIN".into(),
+            value: 73.0,
+            kind: Some("int".into()),
+            min: None,
+            max: Some(200.0),
+            step: Some(10.0),
+            tail: vec![],
+        };
+        let after = UciOption {
+            name: "RFP_MARGIN".into(),
+            value: 78.0,
+            kind: None,
+            min: None,
+            max: None,
+            step: None,
+            tail: vec![],
+        };
+
+        assert_eq!(config_line(&before, &after), "RFP_MARGIN, int, 78, , 200, 10");
+    }
 }